@@ -0,0 +1,18 @@
+//! Aggregated config-validation error, shared by both health-check
+//! binaries so both report every invalid/missing var at once
+//! instead of failing on the first one.
+
+use std::fmt;
+
+#[derive(Debug)]
+pub struct ConfigError {
+    pub errors: Vec<String>,
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid configuration: {}", self.errors.join("; "))
+    }
+}
+
+impl std::error::Error for ConfigError {}
@@ -0,0 +1,31 @@
+//! Env-var parsing helpers shared by both health-check binaries.
+
+use std::env;
+use std::fmt;
+use std::str::FromStr;
+
+/// Parses an env var into `T`, falling back to `default` when unset.
+/// Returns a descriptive error (rather than panicking) when the var is set
+/// but not parseable.
+pub fn parse_env_or<T>(key: &str, default: T) -> Result<T, String>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    match env::var(key) {
+        Ok(val) => val
+            .parse()
+            .map_err(|err| format!("{}={:?} is invalid: {}", key, val, err)),
+        Err(_) => Ok(default),
+    }
+}
+
+pub fn collect<T: Default>(result: Result<T, String>, errors: &mut Vec<String>) -> T {
+    match result {
+        Ok(value) => value,
+        Err(err) => {
+            errors.push(err);
+            T::default()
+        }
+    }
+}
@@ -0,0 +1,21 @@
+//! Optional-env-var parsing, split out from `env_parse.rs` so binaries that
+//! have no optional settings don't pull in an unused function.
+
+use std::env;
+use std::fmt;
+use std::str::FromStr;
+
+/// Parses an optional env var into `T`, returning `None` when unset.
+pub fn parse_env_opt<T>(key: &str) -> Result<Option<T>, String>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    match env::var(key) {
+        Ok(val) => val
+            .parse()
+            .map(Some)
+            .map_err(|err| format!("{}={:?} is invalid: {}", key, val, err)),
+        Err(_) => Ok(None),
+    }
+}
@@ -3,26 +3,87 @@ use rouille::Response;
 use rouille::Server;
 use std::env;
 
+#[path = "../../../common/config_error.rs"]
+mod config_error;
+#[path = "../../../common/env_parse.rs"]
+mod env_parse;
+use config_error::ConfigError;
+use env_parse::{collect, parse_env_or};
+
 fn main() {
-    start_health_check_server();
+    match Config::from_env() {
+        Ok(config) => start_health_check_server(config),
+        Err(err) => {
+            eprintln!("{}", err);
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Env-derived settings for this probe, parsed and validated once at
+/// startup instead of re-read ad hoc by `health_check_handler`.
+struct Config {
+    tls: bool,
+    node_port: u16,
+    health_check_port: u16,
+    admin_password: String,
+    node_external_dns: Option<String>,
+}
+
+impl Config {
+    fn from_env() -> Result<Self, ConfigError> {
+        let mut errors = Vec::new();
+
+        let tls = env::var("TLS").map(|v| v == "true").unwrap_or(false);
+        let node_port = collect(parse_env_or("NODE_PORT", 6379u16), &mut errors);
+        let health_check_port = collect(parse_env_or("HEALTH_CHECK_PORT", 8081u16), &mut errors);
+        let admin_password = env::var("ADMIN_PASSWORD").unwrap_or_default();
+        let node_external_dns = env::var("NODE_EXTERNAL_DNS").ok();
+
+        if tls && node_external_dns.is_none() {
+            errors.push("NODE_EXTERNAL_DNS is required when TLS=true".to_string());
+        }
+
+        if !errors.is_empty() {
+            return Err(ConfigError { errors });
+        }
+
+        Ok(Config {
+            tls,
+            node_port,
+            health_check_port,
+            admin_password,
+            node_external_dns,
+        })
+    }
+
+    fn redis_url(&self) -> String {
+        if self.tls {
+            format!(
+                "rediss://:{}@{}:{}",
+                self.admin_password,
+                self.node_external_dns.as_deref().unwrap_or_default(),
+                self.node_port
+            )
+        } else {
+            format!("redis://:{}@localhost:{}", self.admin_password, self.node_port)
+        }
+    }
 }
 
-fn start_health_check_server() {
-    let port = match env::var("HEALTH_CHECK_PORT") {
-        Ok(port) => port,
-        Err(_) => "8081".to_string(),
-    };
-    let addr = format!("localhost:{}", port);
+fn start_health_check_server(config: Config) {
+    let addr = format!("localhost:{}", config.health_check_port);
 
-    let server = Server::new(addr, |request| {
+    let server = Server::new(addr, move |request| {
         router!(request,
             (GET) (/healthcheck) => {
-              let health = health_check_handler().unwrap();
-
-                if health.eq(&true) {
-                    Response::text("OK")
-                } else {
-                    Response::text("Not ready").with_status_code(500)
+                match health_check_handler(&config) {
+                    Ok(true) => Response::text("OK"),
+                    Ok(false) => Response::text("Not ready").with_status_code(500),
+                    Err(err) => {
+                        eprintln!("Health check failed: {}", err);
+                        Response::text(format!("Not ready: {}", err)).with_status_code(500)
+                    }
                 }
             },
             _ => Response::empty_404()
@@ -33,30 +94,8 @@ fn start_health_check_server() {
     server.run();
 }
 
-fn health_check_handler() -> Result<bool, redis::RedisError> {
-    let password = match env::var("ADMIN_PASSWORD") {
-        Ok(password) => password,
-        Err(_) => "".to_string(),
-    };
-
-    let node_port = match env::var("NODE_PORT") {
-        Ok(port) => port,
-        Err(_) => "6379".to_string(),
-    };
-
-    let redis_url = match env::var("TLS") {
-        Ok(tls) => {
-            if tls == "true" {
-                let url = env::var("NODE_EXTERNAL_DNS").unwrap();
-                format!("rediss://:{}@{}:{}", password, url, node_port)
-            } else {
-                format!("redis://:{}@localhost:{}", password, node_port)
-            }
-        }
-        Err(_) => format!("redis://:{}@localhost:{}", password, node_port),
-    };
-
-    let client: redis::Client = redis::Client::open(redis_url)?;
+fn health_check_handler(config: &Config) -> Result<bool, redis::RedisError> {
+    let client: redis::Client = redis::Client::open(config.redis_url())?;
 
     let mut con = client.get_connection()?;
 
@@ -1,33 +1,180 @@
+use r2d2::{ManageConnection, Pool};
+use redis::IntoConnectionInfo;
 use rouille::{router, Response, Server};
+use std::collections::HashMap;
 use std::env;
+use std::path::PathBuf;
+use std::str::FromStr;
 use std::time::{Duration, Instant};
+use strum::EnumString;
+
+#[path = "../../common/config_error.rs"]
+mod config_error;
+#[path = "../../common/env_parse.rs"]
+mod env_parse;
+#[path = "../../common/env_parse_opt.rs"]
+mod env_parse_opt;
+use config_error::ConfigError;
+use env_parse::{collect, parse_env_or};
+use env_parse_opt::parse_env_opt;
+
+type RedisPool = Pool<RedisConnectionManager>;
 
 fn main() {
     let args: Vec<String> = env::args().collect();
-    let is_sentinel = args.get(1).map_or(false, |arg| arg == "sentinel");
-    start_health_check_server(is_sentinel);
+
+    match Config::from_env(&args) {
+        Ok(config) => start_health_check_server(config),
+        Err(err) => {
+            eprintln!("{}", err);
+            std::process::exit(1);
+        }
+    }
 }
 
-fn start_health_check_server(is_sentinel: bool) {
-    let redis_client = get_redis_client(is_sentinel).unwrap();
-    let port = env::var(if is_sentinel {
-        "HEALTH_CHECK_PORT_SENTINEL"
-    } else {
-        "HEALTH_CHECK_PORT"
+/// Which node type this process is probing. Derived from argv\[1\]: `sentinel`
+/// (in any case) selects `Sentinel`; anything else, or no argument, falls
+/// back to `Node`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumString)]
+#[strum(ascii_case_insensitive)]
+enum CheckMode {
+    Node,
+    Sentinel,
+}
+
+impl CheckMode {
+    fn from_args(args: &[String]) -> Self {
+        args.get(1)
+            .and_then(|arg| CheckMode::from_str(arg).ok())
+            .unwrap_or(CheckMode::Node)
+    }
+}
+
+/// All environment-derived settings, parsed and validated once at startup so
+/// the rest of the program works with typed fields instead of scattered
+/// `env::var` calls.
+struct Config {
+    role: CheckMode,
+    tls: bool,
+    node_host: String,
+    node_port: u16,
+    sentinel_port: u16,
+    random_node_port: Option<u16>,
+    node_unix_socket: Option<PathBuf>,
+    admin_password: String,
+    health_check_port: u16,
+    health_check_port_sentinel: u16,
+    skip_health_check: bool,
+    pool_max_size: u32,
+    pool_min_idle: Option<u32>,
+    pool_connection_timeout: Duration,
+    connect_timeout: Duration,
+    command_timeout: Duration,
+}
+
+fn get_redis_password() -> String {
+    env::var("ADMIN_PASSWORD").unwrap_or_else(|_| {
+        std::fs::read_to_string("/run/secrets/adminpassword")
+            .map(|s| s.trim().to_string())
+            .unwrap_or_default()
     })
-    .unwrap_or_else(|_| {
-        if is_sentinel {
-            "8082".to_string()
-        } else {
-            "8081".to_string()
+}
+
+impl Config {
+    fn from_env(args: &[String]) -> Result<Self, ConfigError> {
+        let mut errors = Vec::new();
+
+        let role = CheckMode::from_args(args);
+        let tls = env::var("TLS").map(|v| v == "true").unwrap_or(false);
+        let node_host = env::var("NODE_HOST").unwrap_or_else(|_| "localhost".to_string());
+        let node_port = collect(parse_env_or("NODE_PORT", 6379u16), &mut errors);
+        let sentinel_port = collect(parse_env_or("SENTINEL_PORT", 26379u16), &mut errors);
+        let random_node_port = collect(parse_env_opt::<u16>("RANDOM_NODE_PORT"), &mut errors);
+        let node_unix_socket = env::var("NODE_UNIX_SOCKET").ok().map(PathBuf::from);
+        let admin_password = get_redis_password();
+        let health_check_port = collect(parse_env_or("HEALTH_CHECK_PORT", 8081u16), &mut errors);
+        let health_check_port_sentinel =
+            collect(parse_env_or("HEALTH_CHECK_PORT_SENTINEL", 8082u16), &mut errors);
+        let skip_health_check = env::var("SKIP_HEALTH_CHECK")
+            .map(|v| v == "true")
+            .unwrap_or(false);
+        let pool_max_size = collect(parse_env_or("HEALTH_CHECK_POOL_MAX_SIZE", 10u32), &mut errors);
+        let pool_min_idle = collect(parse_env_opt::<u32>("HEALTH_CHECK_POOL_MIN_IDLE"), &mut errors);
+        let pool_connection_timeout = Duration::from_millis(collect(
+            parse_env_or("HEALTH_CHECK_POOL_CONNECTION_TIMEOUT_MS", 5000u64),
+            &mut errors,
+        ));
+        let connect_timeout = Duration::from_millis(collect(
+            parse_env_or("HEALTH_CHECK_CONNECT_TIMEOUT_MS", 3000u64),
+            &mut errors,
+        ));
+        let command_timeout = Duration::from_millis(collect(
+            parse_env_or("HEALTH_CHECK_COMMAND_TIMEOUT_MS", 3000u64),
+            &mut errors,
+        ));
+
+        if role == CheckMode::Node && tls && node_unix_socket.is_some() {
+            errors.push(
+                "TLS is not supported over a Unix domain socket (NODE_UNIX_SOCKET)".to_string(),
+            );
+        }
+
+        if let Some(min_idle) = pool_min_idle {
+            if min_idle > pool_max_size {
+                errors.push(format!(
+                    "HEALTH_CHECK_POOL_MIN_IDLE ({}) must be no larger than HEALTH_CHECK_POOL_MAX_SIZE ({})",
+                    min_idle, pool_max_size
+                ));
+            }
+        }
+
+        if !errors.is_empty() {
+            return Err(ConfigError { errors });
+        }
+
+        Ok(Config {
+            role,
+            tls,
+            node_host,
+            node_port,
+            sentinel_port,
+            random_node_port,
+            node_unix_socket,
+            admin_password,
+            health_check_port,
+            health_check_port_sentinel,
+            skip_health_check,
+            pool_max_size,
+            pool_min_idle,
+            pool_connection_timeout,
+            connect_timeout,
+            command_timeout,
+        })
+    }
+
+    fn node_port(&self) -> u16 {
+        match self.role {
+            CheckMode::Node => self.node_port,
+            CheckMode::Sentinel => self.sentinel_port,
+        }
+    }
+
+    fn health_check_port(&self) -> u16 {
+        match self.role {
+            CheckMode::Node => self.health_check_port,
+            CheckMode::Sentinel => self.health_check_port_sentinel,
         }
-    });
+    }
+}
+
+fn start_health_check_server(config: Config) {
+    let redis_pool = get_redis_client(&config).unwrap();
+    let addr = format!("localhost:{}", config.health_check_port());
 
-    let addr = format!("localhost:{}", port);
     let server = Server::new(addr, move |request| {
         router!(request,
-            (GET) (/liveness) => { handle_health_check(is_sentinel, check_handler_liveness, &redis_client) },
-            (GET) (/readiness) => { handle_health_check(is_sentinel, check_handler_readiness, &redis_client) },
+            (GET) (/liveness) => { handle_health_check(&config, check_handler_liveness, &redis_pool) },
+            (GET) (/readiness) => { handle_health_check(&config, check_handler_readiness, &redis_pool) },
             (GET) (/startup) => { Response::text("OK") },
             _ => Response::empty_404()
         )
@@ -37,56 +184,88 @@ fn start_health_check_server(is_sentinel: bool) {
     server.run();
 }
 
-fn handle_health_check<F>(is_sentinel: bool, check_fn: F, redis_pool: &redis::Client) -> Response
+fn handle_health_check<F>(config: &Config, check_fn: F, redis_pool: &RedisPool) -> Response
 where
-    F: Fn(bool, &redis::Client) -> Result<bool, redis::RedisError>,
+    F: Fn(&Config, &RedisPool) -> Result<bool, redis::RedisError>,
 {
-    if env::var("SKIP_HEALTH_CHECK").as_deref() == Ok("true") {
+    if config.skip_health_check {
         return Response::text("OK");
     }
-    let res = check_fn(is_sentinel, redis_pool);
+    match check_fn(config, redis_pool) {
+        Ok(_) => Response::text("OK"),
+        Err(err) => {
+            eprintln!("Health check failed: {}", err);
+            let status_code = if err.is_timeout() { 503 } else { 500 };
+            Response::text(format!("Not ready: {}", err)).with_status_code(status_code)
+        }
+    }
+}
 
-    if res.is_ok() {
-        Response::text("OK")
-    } else {
-        eprintln!("Health check failed: {}", res.err().unwrap());
-        Response::text("Not ready").with_status_code(500)
+fn check_handler_liveness(_config: &Config, redis_pool: &RedisPool) -> Result<bool, redis::RedisError> {
+    match redis_pool.get() {
+        Ok(mut conn) => {
+            let result = run_liveness_check(&mut conn);
+            if matches!(&result, Err(err) if err.is_timeout()) {
+                conn.mark_broken();
+            }
+            result
+        }
+        Err(err) => {
+            eprintln!("Failed to get connection: {:?}", err);
+            Err(redis::RedisError::from((
+                redis::ErrorKind::IoError,
+                "Failed to get connection",
+            )))
+        }
     }
 }
 
-fn check_handler_liveness(_: bool, redis_pool: &redis::Client) -> Result<bool, redis::RedisError> {
-    let connection = redis_pool.get_connection();
+fn run_liveness_check(conn: &mut redis::Connection) -> Result<bool, redis::RedisError> {
+    let response: redis::RedisResult<String> = redis::cmd("PING").query(conn);
 
-    match connection {
-        Ok(mut conn) => {
-            let response: redis::RedisResult<String> = redis::cmd("PING").query(&mut conn);
+    if response.is_err() {
+        let error = response.err().unwrap();
 
-            if response.is_err() {
-                let error = response.err().unwrap();
+        if error.kind() == redis::ErrorKind::BusyLoadingError {
+            eprintln!("Redis is busy loading data, returning true for liveness check.");
+            return Ok(true);
+        }
 
-                if error.kind() == redis::ErrorKind::BusyLoadingError {
-                    eprintln!("Redis is busy loading data, returning true for liveness check.");
-                    return Ok(true);
-                }
+        if error.is_timeout() {
+            return Err(error);
+        }
 
-                eprintln!("Failed to send PING command: {:?}", error);
-                return Err(redis::RedisError::from((
-                    redis::ErrorKind::IoError,
-                    "Failed to send PING command",
-                )));
-            }
+        eprintln!("Failed to send PING command: {:?}", error);
+        return Err(redis::RedisError::from((
+            redis::ErrorKind::IoError,
+            "Failed to send PING command",
+        )));
+    }
 
-            let value = response.as_ref().unwrap();
+    let value = response.as_ref().unwrap();
 
-            if value.contains("PONG") || value.contains("BUSY") || value.contains("LOADING") {
-                Ok(true)
-            } else {
-                eprintln!("Unexpected PING response: {}", value);
-                Err(redis::RedisError::from((
-                    redis::ErrorKind::ResponseError,
-                    "Unexpected PING response",
-                )))
+    if value.contains("PONG") || value.contains("BUSY") || value.contains("LOADING") {
+        Ok(true)
+    } else {
+        eprintln!("Unexpected PING response: {}", value);
+        Err(redis::RedisError::from((
+            redis::ErrorKind::ResponseError,
+            "Unexpected PING response",
+        )))
+    }
+}
+
+fn check_handler_readiness(
+    config: &Config,
+    redis_pool: &RedisPool,
+) -> Result<bool, redis::RedisError> {
+    match redis_pool.get() {
+        Ok(mut con) => {
+            let result = run_readiness_check(config, &mut con);
+            if matches!(&result, Err(err) if err.is_timeout()) {
+                con.mark_broken();
             }
+            result
         }
         Err(err) => {
             eprintln!("Failed to get connection: {:?}", err);
@@ -98,99 +277,243 @@ fn check_handler_liveness(_: bool, redis_pool: &redis::Client) -> Result<bool, r
     }
 }
 
-fn check_handler_readiness(
-    is_sentinel: bool,
-    redis_pool: &redis::Client,
-) -> Result<bool, redis::RedisError> {
-    if let Ok(mut con) = redis_pool.get_connection() {
-        if is_sentinel {
-            return check_sentinel(&mut con);
-        }
+fn run_readiness_check(config: &Config, con: &mut redis::Connection) -> Result<bool, redis::RedisError> {
+    if config.role == CheckMode::Sentinel {
+        return check_sentinel(con);
+    }
 
-        let db_info: String = redis::cmd("INFO").query(&mut con)?;
-        if db_info.contains("cluster_enabled:1") {
-            return get_status_from_cluster_node_readiness(&mut con);
-        }
-        check_node_readiness(&db_info, &mut con)
-    } else {
-        Err(redis::RedisError::from((
-            redis::ErrorKind::IoError,
-            "Failed to get connection",
-        )))
+    let raw_info: String = redis::cmd("INFO").query(con)?;
+    let info = InfoReport::parse(&raw_info);
+
+    if info.get("cluster_enabled") == Some("1") {
+        return get_status_from_cluster_node_readiness(con);
     }
+    check_node_readiness(&info, con)
 }
 
-fn get_redis_client(is_sentinel: bool) -> Result<redis::Client, redis::RedisError> {
-    let password = get_redis_password();
-    let node_port = get_node_port(is_sentinel);
-    let redis_url = get_redis_url(&password, &node_port);
+/// A pooled connection with read/write timeouts already applied, plus a
+/// flag the caller sets when a command times out mid-flight. A timed-out
+/// socket may be protocol-desynced (partial write sent / partial reply
+/// buffered), so it must never be handed to another probe.
+struct TimedConnection {
+    inner: redis::Connection,
+    broken: bool,
+}
 
-    let client = redis::Client::open(redis_url).map_err(|err| {
+impl TimedConnection {
+    fn mark_broken(&mut self) {
+        self.broken = true;
+    }
+}
+
+impl std::ops::Deref for TimedConnection {
+    type Target = redis::Connection;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl std::ops::DerefMut for TimedConnection {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.inner
+    }
+}
+
+/// Manages a pool of `redis::Connection`s for a single `redis::Client`.
+///
+/// `r2d2` calls `connect` to create new pooled connections and `is_valid` to
+/// decide whether an idle connection may still be handed out. Read/write
+/// timeouts are applied once in `connect`, not by the caller after checkout,
+/// so `is_valid`'s `PING` (run on essentially every checkout, since r2d2
+/// defaults to `test_on_check_out`) can't hang on a stalled node either.
+struct RedisConnectionManager {
+    client: redis::Client,
+    connect_timeout: Duration,
+    command_timeout: Duration,
+}
+
+impl RedisConnectionManager {
+    fn new(
+        connection_info: redis::ConnectionInfo,
+        connect_timeout: Duration,
+        command_timeout: Duration,
+    ) -> redis::RedisResult<Self> {
+        Ok(Self {
+            client: redis::Client::open(connection_info)?,
+            connect_timeout,
+            command_timeout,
+        })
+    }
+}
+
+impl ManageConnection for RedisConnectionManager {
+    type Connection = TimedConnection;
+    type Error = redis::RedisError;
+
+    fn connect(&self) -> Result<Self::Connection, Self::Error> {
+        let conn = self.client.get_connection_with_timeout(self.connect_timeout)?;
+        conn.set_read_timeout(Some(self.command_timeout))?;
+        conn.set_write_timeout(Some(self.command_timeout))?;
+        Ok(TimedConnection {
+            inner: conn,
+            broken: false,
+        })
+    }
+
+    fn is_valid(&self, conn: &mut Self::Connection) -> Result<(), Self::Error> {
+        redis::cmd("PING").query(&mut *conn)
+    }
+
+    fn has_broken(&self, conn: &mut Self::Connection) -> bool {
+        conn.broken
+    }
+}
+
+fn get_redis_client(config: &Config) -> Result<RedisPool, redis::RedisError> {
+    let connection_info = get_redis_connection_info(config)?;
+
+    let manager = RedisConnectionManager::new(
+        connection_info,
+        config.connect_timeout,
+        config.command_timeout,
+    )
+    .map_err(|err| {
         eprintln!("Failed to create Redis client: {}", err);
         err
     })?;
 
-    return Ok(client);
+    // `build_unchecked` hands back the pool immediately instead of blocking
+    // (and ultimately failing) until `min_idle` connections succeed: the
+    // sidecar can start before FalkorDB is reachable, and that race must
+    // surface as a failing probe response, not a crashed health-check server.
+    Ok(Pool::builder()
+        .max_size(config.pool_max_size)
+        .min_idle(config.pool_min_idle)
+        .connection_timeout(config.pool_connection_timeout)
+        .build_unchecked(manager))
+}
+
+/// A parsed `INFO` reply: `# Section` headers grouping `key:value` lines,
+/// flattened into section name -> field map. Typed accessors below hide the
+/// section layout from callers that just want a single field.
+struct InfoReport {
+    sections: HashMap<String, HashMap<String, String>>,
+}
+
+impl InfoReport {
+    fn parse(raw: &str) -> Self {
+        let mut sections: HashMap<String, HashMap<String, String>> = HashMap::new();
+        let mut section = "default".to_string();
+
+        for line in raw.lines() {
+            let line = line.trim_end_matches('\r');
+            if let Some(name) = line.strip_prefix('#') {
+                section = name.trim().to_string();
+                continue;
+            }
+            if let Some((key, value)) = line.split_once(':') {
+                sections
+                    .entry(section.clone())
+                    .or_default()
+                    .insert(key.to_string(), value.to_string());
+            }
+        }
+
+        Self { sections }
+    }
+
+    /// Looks up `key` in any section; `INFO` field names are unique across
+    /// sections in practice.
+    fn get(&self, key: &str) -> Option<&str> {
+        self.sections
+            .values()
+            .find_map(|fields| fields.get(key))
+            .map(String::as_str)
+    }
+
+    fn role(&self) -> Option<&str> {
+        self.get("role")
+    }
+
+    fn loading(&self) -> Option<bool> {
+        self.get("loading").map(|v| v == "1")
+    }
+
+    fn master_link_status(&self) -> Option<&str> {
+        self.get("master_link_status")
+    }
+
+    fn master_sync_in_progress(&self) -> Option<bool> {
+        self.get("master_sync_in_progress").map(|v| v == "1")
+    }
+
+    // Exposed for operators/future callers; not currently part of the
+    // readiness decision (bgsave/aof rewrites don't block readiness).
+    #[allow(dead_code)]
+    fn rdb_bgsave_in_progress(&self) -> Option<bool> {
+        self.get("rdb_bgsave_in_progress").map(|v| v == "1")
+    }
+
+    #[allow(dead_code)]
+    fn aof_rewrite_in_progress(&self) -> Option<bool> {
+        self.get("aof_rewrite_in_progress").map(|v| v == "1")
+    }
+}
+
+/// Builds the `redis::RedisError` returned for a well-formed but not-ready
+/// node, carrying `reason` as the error's detail so it reaches both the log
+/// line and the probe response body.
+fn not_ready(reason: impl Into<String>) -> redis::RedisError {
+    redis::RedisError::from((redis::ErrorKind::ResponseError, "Node not ready", reason.into()))
 }
 
 fn check_node_readiness(
-    db_info: &str,
+    info: &InfoReport,
     con: &mut redis::Connection,
 ) -> Result<bool, redis::RedisError> {
-    match get_redis_role(db_info)? {
-        "master" => get_status_from_master_readiness(db_info, con),
-        _ => get_status_from_slave_readiness(db_info, con),
+    match info.role() {
+        Some("master") => get_status_from_master_readiness(info, con),
+        Some(_) => get_status_from_slave_readiness(info, con),
+        None => Err(not_ready("INFO reply is missing the role field")),
     }
 }
 
-fn get_redis_password() -> String {
-    env::var("ADMIN_PASSWORD").unwrap_or_else(|_| {
-        std::fs::read_to_string("/run/secrets/adminpassword")
-            .map(|s| s.trim().to_string())
-            .unwrap_or_default()
-    })
-}
-
-fn get_node_port(is_sentinel: bool) -> String {
-    env::var(if is_sentinel {
-        "SENTINEL_PORT"
-    } else {
-        "NODE_PORT"
-    })
-    .unwrap_or_else(|_| {
-        if is_sentinel {
-            "26379".to_string()
-        } else {
-            "6379".to_string()
+fn get_redis_connection_info(config: &Config) -> Result<redis::ConnectionInfo, redis::RedisError> {
+    // NODE_UNIX_SOCKET only applies to the node probe: a sentinel container
+    // talks to SENTINEL_PORT over TCP, even if the var is set (e.g. via a
+    // ConfigMap shared with the node container).
+    if config.role == CheckMode::Node {
+        if let Some(socket_path) = &config.node_unix_socket {
+            return Ok(redis::ConnectionInfo {
+                addr: redis::ConnectionAddr::Unix(socket_path.clone()),
+                redis: redis::RedisConnectionInfo {
+                    db: 0,
+                    username: None,
+                    password: Some(config.admin_password.clone()),
+                    ..Default::default()
+                },
+            });
         }
-    })
-}
+    }
 
-fn get_redis_url(password: &str, node_port: &str) -> String {
-    let tls = env::var("TLS").unwrap_or_default();
-    let host = env::var("NODE_HOST").unwrap_or_else(|_| "localhost".to_string());
+    let node_port = config.node_port();
 
-    if tls == "true" {
-        resolve_host(&host);
-        let node_port = env::var("RANDOM_NODE_PORT").unwrap_or_else(|_| node_port.to_string());
-        format!("rediss://:{}@{}:{}", password, host, node_port)
+    let redis_url = if config.tls {
+        resolve_host(&config.node_host);
+        let port = config.random_node_port.unwrap_or(node_port);
+        format!("rediss://:{}@{}:{}", config.admin_password, config.node_host, port)
     } else {
-        format!("redis://:{}@localhost:{}", password, node_port)
-    }
+        format!("redis://:{}@localhost:{}", config.admin_password, node_port)
+    };
+
+    redis_url.into_connection_info()
 }
 
 fn check_sentinel(con: &mut redis::Connection) -> Result<bool, redis::RedisError> {
     Ok(redis::cmd("PING").query::<String>(con)? == "PONG")
 }
 
-fn get_redis_role(db_info: &str) -> Result<&str, redis::RedisError> {
-    let role_regex = regex::Regex::new(r"role:(\w+)").unwrap();
-    role_regex
-        .captures(db_info)
-        .and_then(|caps| caps.get(1).map(|m| m.as_str()))
-        .ok_or_else(|| redis::RedisError::from((redis::ErrorKind::ResponseError, "Role not found")))
-}
-
 fn get_status_from_cluster_node_readiness(
     con: &mut redis::Connection,
 ) -> Result<bool, redis::RedisError> {
@@ -201,20 +524,52 @@ fn get_status_from_cluster_node_readiness(
 }
 
 fn get_status_from_master_readiness(
-    db_info: &str,
+    info: &InfoReport,
     con: &mut redis::Connection,
 ) -> Result<bool, redis::RedisError> {
-    Ok(redis::cmd("PING").query::<String>(con)?.contains("PONG") && db_info.contains("loading:0"))
+    if !redis::cmd("PING").query::<String>(con)?.contains("PONG") {
+        return Err(not_ready("PING did not return PONG"));
+    }
+
+    match info.loading() {
+        Some(false) => Ok(true),
+        Some(true) => Err(not_ready("node is still loading the dataset")),
+        None => Err(not_ready("INFO reply is missing the loading field")),
+    }
 }
 
 fn get_status_from_slave_readiness(
-    db_info: &str,
+    info: &InfoReport,
     con: &mut redis::Connection,
 ) -> Result<bool, redis::RedisError> {
-    Ok(redis::cmd("PING").query::<String>(con)?.contains("PONG")
-        && db_info.contains("loading:0")
-        && db_info.contains("master_link_status:up")
-        && db_info.contains("master_sync_in_progress:0"))
+    if !redis::cmd("PING").query::<String>(con)?.contains("PONG") {
+        return Err(not_ready("PING did not return PONG"));
+    }
+
+    match info.loading() {
+        Some(false) => {}
+        Some(true) => return Err(not_ready("node is still loading the dataset")),
+        None => return Err(not_ready("INFO reply is missing the loading field")),
+    }
+
+    match info.master_link_status() {
+        Some("up") => {}
+        Some(other) => {
+            return Err(not_ready(format!(
+                "master_link_status is {}, expected up",
+                other
+            )))
+        }
+        None => return Err(not_ready("INFO reply is missing the master_link_status field")),
+    }
+
+    match info.master_sync_in_progress() {
+        Some(false) => Ok(true),
+        Some(true) => Err(not_ready("initial sync with the master is still in progress")),
+        None => Err(not_ready(
+            "INFO reply is missing the master_sync_in_progress field",
+        )),
+    }
 }
 
 fn resolve_host(host: &str) {